@@ -7,6 +7,8 @@ use crate::mown_str::MownStr;
 use crate::ns::{rdf, xsd};
 use crate::{Iri, Result, Term, TermData, TermError};
 use language_tag::LangTag;
+use lazy_static::lazy_static;
+use regex::Regex;
 use std::convert::TryFrom;
 use std::fmt;
 use std::hash::{Hash, Hasher};
@@ -14,6 +16,8 @@ use std::io;
 
 mod _convert;
 pub use self::_convert::*;
+mod _value;
+pub use self::_value::*;
 
 /// Internal distinction of literals.
 ///
@@ -274,6 +278,122 @@ where
         }
     }
 
+    /// Writes the literal to the `fmt::Write` using the canonical NTriples
+    /// syntax.
+    ///
+    /// Unlike [`write_fmt`](Literal::write_fmt), which only escapes `\n`,
+    /// `\r`, `\\` and `"`, this additionally escapes `\t`, `\b`, `\f` and
+    /// falls back to `\uXXXX`/`\UXXXXXXXX` UCHAR sequences for any other
+    /// control codepoint, as required of a canonical RDF 1.1 form.
+    pub fn write_canonical_fmt<W>(&self, w: &mut W) -> fmt::Result
+    where
+        W: fmt::Write,
+    {
+        w.write_char('"')?;
+        fmt_quoted_string_canonical(w, self.txt.as_ref())?;
+
+        match &self.kind {
+            Lang(tag) => {
+                w.write_str("\"@")?;
+                w.write_str(tag.as_ref())
+            }
+            Dt(dt) => {
+                if &xsd::string != dt {
+                    w.write_str("\"^^")?;
+                    dt.write_fmt(w)
+                } else {
+                    w.write_char('"')
+                }
+            }
+        }
+    }
+
+    /// Writes the literal to the `io::Write` using the canonical NTriples
+    /// syntax.
+    ///
+    /// See [`write_canonical_fmt`](Literal::write_canonical_fmt).
+    pub fn write_canonical_io<W>(&self, w: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        w.write_all(b"\"")?;
+        io_quoted_string_canonical(w, self.txt.as_ref())?;
+
+        match &self.kind {
+            Lang(tag) => {
+                w.write_all(b"\"@")?;
+                w.write_all(tag.as_ref().as_bytes())
+            }
+            Dt(dt) => {
+                if &xsd::string != dt {
+                    w.write_all(b"\"^^")?;
+                    dt.write_io(w)
+                } else {
+                    w.write_all(b"\"")
+                }
+            }
+        }
+    }
+
+    /// Writes the literal to the `fmt::Write` using Turtle's compact
+    /// literal forms where possible, falling back to the quoted NTriples
+    /// form (see [`write_fmt`](Literal::write_fmt)) otherwise.
+    ///
+    /// Emits a bare `true`/`false` for an `xsd:boolean` whose text is
+    /// exactly `true` or `false` (`1`/`0` keep the quoted form), and an unquoted
+    /// numeral for `xsd:integer`/`xsd:decimal`/`xsd:double` when the text
+    /// is exactly in the canonical lexical form Turtle's own `INTEGER` /
+    /// `DECIMAL` / `DOUBLE` tokens require (since, unlike NTriples, a bare
+    /// Turtle numeral always denotes one of those three datatypes, never
+    /// e.g. `xsd:int` or `xsd:float`). Falls back to a triple-quoted long
+    /// string, rather than the usual single-quoted form, when the text
+    /// contains a newline.
+    pub fn write_turtle_fmt<W>(&self, w: &mut W) -> fmt::Result
+    where
+        W: fmt::Write,
+    {
+        if let Dt(dt) = &self.kind {
+            let txt = self.txt.as_ref();
+            if &xsd::boolean == dt && (txt == "true" || txt == "false") {
+                return w.write_str(txt);
+            }
+            if &xsd::integer == dt && INTEGER_RE.is_match(txt) {
+                return w.write_str(txt);
+            }
+            if &xsd::decimal == dt && DECIMAL_RE.is_match(txt) && has_turtle_decimal_point(txt) {
+                return w.write_str(txt);
+            }
+            if &xsd::double == dt
+                && DOUBLE_RE.is_match(txt)
+                && (txt.contains('e') || txt.contains('E'))
+            {
+                return w.write_str(txt);
+            }
+        }
+
+        if !self.txt.as_ref().contains('\n') {
+            return self.write_fmt(w);
+        }
+
+        w.write_str("\"\"\"")?;
+        write_turtle_long_string(w, self.txt.as_ref())?;
+        w.write_str("\"\"\"")?;
+        match &self.kind {
+            Lang(tag) => {
+                w.write_char('@')?;
+                w.write_str(tag.as_ref())
+            }
+            Dt(dt) => {
+                if &xsd::string != dt {
+                    w.write_str("^^")?;
+                    dt.write_fmt(w)
+                } else {
+                    Ok(())
+                }
+            }
+        }
+    }
+
     /// Return this literal's lexical value as text.
     pub fn value(&self) -> MownStr {
         self.txt().as_ref().into()
@@ -303,6 +423,42 @@ where
         }
     }
 
+    /// Check whether this literal's language tag matches `range`, per
+    /// [RFC 4647](https://tools.ietf.org/html/rfc4647) *extended
+    /// filtering*.
+    ///
+    /// `range` is a sequence of subtags separated by `-`, where `*` stands
+    /// for the wildcard subtag; e.g. `"en-*"` matches `"en"`, `"en-US"` and
+    /// `"en-Latn-US"` but not `"english"` or `"fr"`. The first subtags of
+    /// `range` and of this literal's tag must match (or `range`'s must be
+    /// `*`); each subsequent non-wildcard range subtag must then appear, in
+    /// order, among the tag's remaining subtags (non-matching tag subtags
+    /// are skipped over, but a range subtag that can't be found anywhere
+    /// fails the match), while a `*` range subtag simply moves on to the
+    /// next one, without requiring or consuming any particular tag subtag.
+    ///
+    /// A literal without a language tag never matches.
+    pub fn lang_matches(&self, range: &str) -> bool {
+        match &self.kind {
+            Lang(tag) => lang_range_matches_extended(tag.as_ref(), range),
+            Dt(_) => false,
+        }
+    }
+
+    /// Check whether this literal's language tag matches `range`, per
+    /// [RFC 4647](https://tools.ietf.org/html/rfc4647) *basic filtering*:
+    /// `range` matches the tag if it equals the tag, or equals a prefix of
+    /// the tag that ends at a subtag boundary, case-insensitively; the
+    /// wildcard range `"*"` matches any tag.
+    ///
+    /// A literal without a language tag never matches.
+    pub fn lang_matches_basic(&self, range: &str) -> bool {
+        match &self.kind {
+            Lang(tag) => lang_range_matches_basic(tag.as_ref(), range),
+            Dt(_) => false,
+        }
+    }
+
     /// Check if the datatype IRI is absolute.
     pub fn is_absolute(&self) -> bool {
         if let Dt(dt) = &self.kind {
@@ -320,6 +476,181 @@ where
     {
         self.txt().as_ref() == other.txt().as_ref()
     }
+
+    /// Extract this literal's value, in value space, as a native Rust type.
+    ///
+    /// This applies the lexical-to-value mapping of this literal's
+    /// datatype, as described in the [module documentation](self).
+    ///
+    /// # Error
+    ///
+    /// Returns `Err` if the text is not in the lexical space `T` expects
+    /// for this literal's datatype (including the case where the datatype
+    /// is not one `T` supports at all), rather than panicking: the rest of
+    /// the crate deliberately accepts ill-typed literals.
+    pub fn value_as<T>(&self) -> Result<T>
+    where
+        T: FromLexical,
+    {
+        T::from_lexical(self.txt.as_ref(), self.dt())
+    }
+
+    /// Check whether this literal's text lies in the lexical space of its
+    /// datatype.
+    ///
+    /// Datatypes outside the table of core XSD datatypes built into this
+    /// crate (including unknown custom IRIs) are always considered
+    /// well-typed, as the RDF specification requires implementations to
+    /// accept literals of arbitrary datatypes. `rdf:langString` literals
+    /// are well-typed iff their language tag is valid
+    /// [BCP47](https://tools.ietf.org/html/bcp47) (the same check performed
+    /// by [`new_lang`](Literal::new_lang)).
+    pub fn is_well_typed(&self) -> bool {
+        match &self.kind {
+            Lang(tag) => tag.as_ref().parse::<LangTag>().is_ok(),
+            Dt(dt) => match lexical_space_regex(dt.as_ref_str()) {
+                Some(re) => re.is_match(self.txt.as_ref()),
+                None => true,
+            },
+        }
+    }
+
+    /// Check whether this literal is well-typed, as an `Err` rather than a
+    /// `bool`.
+    ///
+    /// See [`is_well_typed`](Literal::is_well_typed).
+    pub fn validate(&self) -> Result<()> {
+        if self.is_well_typed() {
+            Ok(())
+        } else {
+            Err(TermError::InvalidLexicalForm {
+                txt: self.txt.as_ref().to_owned(),
+                dt: self.dt().to_string(),
+            })
+        }
+    }
+
+    /// Check if both literals denote the same value, in value space,
+    /// rather than the same lexical representation (which is what
+    /// `PartialEq` checks).
+    ///
+    /// This implements RDF *value* equality: numeric literals are promoted
+    /// across the integer/decimal/double hierarchy before comparing (so
+    /// `"1"^^xsd:integer`, `"1.0"^^xsd:decimal` and `"1.0E0"^^xsd:double`
+    /// are all equal), `xsd:dateTime`/`xsd:date` literals compare by the
+    /// instant they denote, and `xsd:boolean` literals compare their
+    /// boolean value. Language-tagged literals compare text plus
+    /// case-insensitive tag, same as term equality. When either side is
+    /// ill-typed, or of a datatype this mapping doesn't know, this falls
+    /// back to term equality.
+    pub fn value_eq<U>(&self, other: &Literal<U>) -> bool
+    where
+        U: TermData,
+    {
+        if let (Some(sv), Some(ov)) = (self.numeric_value(), other.numeric_value()) {
+            return sv == ov;
+        }
+        if self.dt() == xsd::dateTime || self.dt() == xsd::date {
+            if let (Ok(sdt), Ok(odt)) = (
+                self.value_as::<XsdDateTime>(),
+                other.value_as::<XsdDateTime>(),
+            ) {
+                return sdt.to_utc_key() == odt.to_utc_key();
+            }
+        }
+        if self.dt() == xsd::boolean && other.dt() == xsd::boolean {
+            if let (Ok(sb), Ok(ob)) = (self.value_as::<bool>(), other.value_as::<bool>()) {
+                return sb == ob;
+            }
+        }
+        self == other
+    }
+
+    /// This literal's value in the numeric hierarchy (`xsd:integer`,
+    /// `xsd:decimal`, `xsd:double`/`xsd:float`), if it has one and is
+    /// well-typed. Used by [`value_eq`](Literal::value_eq).
+    fn numeric_value(&self) -> Option<NumericValue> {
+        let dt = self.dt();
+        if dt == xsd::integer || dt == xsd::int || dt == xsd::long {
+            self.value_as::<i128>().ok().map(NumericValue::Integer)
+        } else if dt == xsd::decimal {
+            self.value_as::<f64>().ok().map(NumericValue::Decimal)
+        } else if dt == xsd::double || dt == xsd::float {
+            self.value_as::<f64>().ok().map(NumericValue::Double)
+        } else {
+            None
+        }
+    }
+}
+
+/// A numeric literal's value, tagged with where in the
+/// integer/decimal/double hierarchy it came from so that equality can
+/// promote across it.
+#[derive(Clone, Copy, Debug)]
+enum NumericValue {
+    Integer(i128),
+    Decimal(f64),
+    Double(f64),
+}
+
+impl NumericValue {
+    fn as_f64(self) -> f64 {
+        match self {
+            NumericValue::Integer(i) => i as f64,
+            NumericValue::Decimal(d) | NumericValue::Double(d) => d,
+        }
+    }
+}
+
+impl PartialEq for NumericValue {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (NumericValue::Integer(a), NumericValue::Integer(b)) => a == b,
+            _ => self.as_f64() == other.as_f64(),
+        }
+    }
+}
+
+lazy_static! {
+    /// Lexical space of `xsd:boolean`.
+    static ref BOOLEAN_RE: Regex = Regex::new(r"^(true|false|0|1)$").unwrap();
+    /// Lexical space of `xsd:integer` (and, for this crate's purposes,
+    /// `xsd:int` and `xsd:long`).
+    static ref INTEGER_RE: Regex = Regex::new(r"^[+-]?[0-9]+$").unwrap();
+    /// Lexical space of `xsd:decimal`.
+    static ref DECIMAL_RE: Regex = Regex::new(r"^[+-]?([0-9]+(\.[0-9]*)?|\.[0-9]+)$").unwrap();
+    /// Lexical space of `xsd:double` and `xsd:float`.
+    static ref DOUBLE_RE: Regex = Regex::new(
+        r"^([+-]?([0-9]+(\.[0-9]*)?|\.[0-9]+)([eE][+-]?[0-9]+)?|INF|-INF|NaN)$"
+    ).unwrap();
+    /// Lexical space of `xsd:dateTime`.
+    static ref DATE_TIME_RE: Regex = Regex::new(
+        r"^-?[0-9]{4,}-[0-9]{2}-[0-9]{2}T[0-9]{2}:[0-9]{2}:[0-9]{2}(\.[0-9]+)?(Z|[+-][0-9]{2}:[0-9]{2})?$"
+    ).unwrap();
+    /// Lexical space of `xsd:date`.
+    static ref DATE_RE: Regex = Regex::new(
+        r"^-?[0-9]{4,}-[0-9]{2}-[0-9]{2}(Z|[+-][0-9]{2}:[0-9]{2})?$"
+    ).unwrap();
+}
+
+/// Return the regex anchoring the lexical space of `dt`, or `None` if `dt`
+/// is not one of the core XSD datatypes this crate validates.
+fn lexical_space_regex(dt: Iri<&str>) -> Option<&'static Regex> {
+    if dt == xsd::boolean {
+        Some(&BOOLEAN_RE)
+    } else if dt == xsd::integer || dt == xsd::int || dt == xsd::long {
+        Some(&INTEGER_RE)
+    } else if dt == xsd::decimal {
+        Some(&DECIMAL_RE)
+    } else if dt == xsd::double || dt == xsd::float {
+        Some(&DOUBLE_RE)
+    } else if dt == xsd::dateTime {
+        Some(&DATE_TIME_RE)
+    } else if dt == xsd::date {
+        Some(&DATE_RE)
+    } else {
+        None
+    }
 }
 
 impl<TD> fmt::Display for Literal<TD>
@@ -400,6 +731,54 @@ impl<TD: TermData> Hash for Literal<TD> {
     }
 }
 
+/// RFC 4647 basic filtering: `range` matches `tag` iff they're equal, or
+/// `range` is a prefix of `tag` ending at a `-` boundary, case-insensitively
+/// (with `"*"` matching everything).
+fn lang_range_matches_basic(tag: &str, range: &str) -> bool {
+    if range == "*" {
+        return true;
+    }
+    if tag.eq_ignore_ascii_case(range) {
+        return true;
+    }
+    let tag = tag.to_ascii_lowercase();
+    let mut prefix = range.to_ascii_lowercase();
+    prefix.push('-');
+    tag.starts_with(&prefix)
+}
+
+/// RFC 4647 extended filtering: see
+/// [`Literal::lang_matches`](Literal::lang_matches) for the algorithm.
+fn lang_range_matches_extended(tag: &str, range: &str) -> bool {
+    let tag_subtags: Vec<&str> = tag.split('-').collect();
+    let range_subtags: Vec<&str> = range.split('-').collect();
+
+    let (range_first, range_rest) = match range_subtags.split_first() {
+        Some(split) => split,
+        None => return false,
+    };
+    let (tag_first, mut tag_rest) = match tag_subtags.split_first() {
+        Some((first, rest)) => (first, rest),
+        None => return false,
+    };
+    if *range_first != "*" && !range_first.eq_ignore_ascii_case(tag_first) {
+        return false;
+    }
+
+    for range_sub in range_rest {
+        if *range_sub == "*" {
+            // A wildcard subtag just moves on to the next range subtag; it
+            // doesn't have to consume anything from the tag itself.
+            continue;
+        }
+        match tag_rest.iter().position(|t| t.eq_ignore_ascii_case(range_sub)) {
+            Some(pos) => tag_rest = &tag_rest[pos + 1..],
+            None => return false,
+        }
+    }
+    true
+}
+
 fn fmt_quoted_string<W: fmt::Write>(w: &mut W, txt: &str) -> fmt::Result {
     let mut cut = txt.len();
     let mut cutchar = '\0';
@@ -471,6 +850,98 @@ fn io_quoted_string<W: io::Write>(w: &mut W, txt: &[u8]) -> io::Result<()> {
     }
 }
 
+/// Writes `cp` as a `\uXXXX` (or `\UXXXXXXXX`, for codepoints outside the
+/// BMP) UCHAR escape sequence.
+fn write_uchar<W: fmt::Write>(w: &mut W, cp: char) -> fmt::Result {
+    let cp = cp as u32;
+    if cp <= 0xFFFF {
+        write!(w, "\\u{:04X}", cp)
+    } else {
+        write!(w, "\\U{:08X}", cp)
+    }
+}
+
+fn write_uchar_io<W: io::Write>(w: &mut W, cp: char) -> io::Result<()> {
+    let cp = cp as u32;
+    if cp <= 0xFFFF {
+        write!(w, "\\u{:04X}", cp)
+    } else {
+        write!(w, "\\U{:08X}", cp)
+    }
+}
+
+/// Like [`fmt_quoted_string`], but also escapes `\t`, `\b`, `\f` and falls
+/// back to UCHAR sequences for any other control codepoint, producing the
+/// canonical NTriples form required by
+/// [`write_canonical_fmt`](Literal::write_canonical_fmt).
+fn fmt_quoted_string_canonical<W: fmt::Write>(w: &mut W, txt: &str) -> fmt::Result {
+    for chr in txt.chars() {
+        match chr {
+            '\\' => w.write_str("\\\\")?,
+            '"' => w.write_str("\\\"")?,
+            '\n' => w.write_str("\\n")?,
+            '\r' => w.write_str("\\r")?,
+            '\t' => w.write_str("\\t")?,
+            '\u{08}' => w.write_str("\\b")?,
+            '\u{0C}' => w.write_str("\\f")?,
+            c if c.is_control() => write_uchar(w, c)?,
+            c => w.write_char(c)?,
+        }
+    }
+    Ok(())
+}
+
+/// See [`fmt_quoted_string_canonical`].
+fn io_quoted_string_canonical<W: io::Write>(w: &mut W, txt: &str) -> io::Result<()> {
+    for chr in txt.chars() {
+        match chr {
+            '\\' => w.write_all(b"\\\\")?,
+            '"' => w.write_all(b"\\\"")?,
+            '\n' => w.write_all(b"\\n")?,
+            '\r' => w.write_all(b"\\r")?,
+            '\t' => w.write_all(b"\\t")?,
+            '\u{08}' => w.write_all(b"\\b")?,
+            '\u{0C}' => w.write_all(b"\\f")?,
+            c if c.is_control() => write_uchar_io(w, c)?,
+            c => write!(w, "{}", c)?,
+        }
+    }
+    Ok(())
+}
+
+/// Check whether `txt` has a `.` immediately followed by at least one
+/// digit, as Turtle's `DECIMAL` token (`[+-]? [0-9]* '.' [0-9]+`) requires.
+///
+/// `xsd:decimal`'s own lexical space allows a bare trailing dot (e.g.
+/// `"5."`), which `DECIMAL_RE` accepts, but that's not a legal Turtle
+/// numeral: writing it unquoted risks the `.` being read as the statement
+/// terminator.
+fn has_turtle_decimal_point(txt: &str) -> bool {
+    match txt.find('.') {
+        Some(pos) => txt[pos + 1..]
+            .chars()
+            .next()
+            .map_or(false, |c| c.is_ascii_digit()),
+        None => false,
+    }
+}
+
+/// Escapes the body of a Turtle triple-quoted long string: backslashes and
+/// double quotes (which could otherwise prematurely close the `"""`
+/// delimiter) are escaped; literal newlines are kept as-is, which is the
+/// whole point of the long-string form.
+fn write_turtle_long_string<W: fmt::Write>(w: &mut W, txt: &str) -> fmt::Result {
+    for chr in txt.chars() {
+        match chr {
+            '\\' => w.write_str("\\\\")?,
+            '"' => w.write_str("\\\"")?,
+            '\r' => w.write_str("\\r")?,
+            c => w.write_char(c)?,
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod test {
     // Most of the code from this module is tested through its use in other modules
@@ -535,4 +1006,215 @@ mod test {
             mapped.clone_into::<std::sync::Arc<str>>()
         );
     }
+
+    #[test]
+    fn value_as_date_time() {
+        let lit = Literal::new_dt("2020-01-01T10:00:00Z", xsd::iri::dateTime.clone());
+        let dt = lit.value_as::<XsdDateTime>().unwrap();
+        assert_eq!(dt.year, 2020);
+        assert_eq!(dt.month, 1);
+        assert_eq!(dt.day, 1);
+        assert_eq!(dt.hour, 10);
+        assert_eq!(dt.minute, 0);
+        assert_eq!(dt.second, 0);
+        assert_eq!(dt.offset_minutes, Some(0));
+    }
+
+    #[test]
+    fn value_as_date_time_with_offset_and_fraction() {
+        let lit = Literal::new_dt("2020-01-01T10:00:00.5+01:00", xsd::iri::dateTime.clone());
+        let dt = lit.value_as::<XsdDateTime>().unwrap();
+        assert_eq!(dt.second, 0);
+        assert_eq!(dt.nanosecond, 500_000_000);
+        assert_eq!(dt.offset_minutes, Some(60));
+    }
+
+    #[test]
+    fn value_as_rejects_ill_typed_integer() {
+        let lit = Literal::new_dt("not a number", xsd::iri::integer.clone());
+        assert!(lit.value_as::<i64>().is_err());
+    }
+
+    #[test]
+    fn is_well_typed_detects_malformed_integer() {
+        let lit = Literal::new_dt("abc", xsd::iri::integer.clone());
+        assert!(!lit.is_well_typed());
+        assert!(lit.validate().is_err());
+    }
+
+    #[test]
+    fn is_well_typed_accepts_well_typed_literals() {
+        let boolean = Literal::new_dt("true", xsd::iri::boolean.clone());
+        let decimal = Literal::new_dt("-12.34", xsd::iri::decimal.clone());
+        assert!(boolean.is_well_typed());
+        assert!(boolean.validate().is_ok());
+        assert!(decimal.is_well_typed());
+    }
+
+    #[test]
+    fn is_well_typed_accepts_unknown_datatype() {
+        let dt = Iri::<&str>::new("http://example.org/mystery").unwrap();
+        let lit = Literal::new_dt("whatever, it's not in the table", dt);
+        assert!(lit.is_well_typed());
+    }
+
+    #[test]
+    fn is_well_typed_checks_lang_tag() {
+        let lit = Literal::new_lang("hello", "en-US").unwrap();
+        assert!(lit.is_well_typed());
+    }
+
+    #[test]
+    fn value_eq_promotes_across_numeric_hierarchy() {
+        let one_int = Literal::new_dt("01", xsd::iri::integer.clone());
+        let one_int_canonical = Literal::new_dt("1", xsd::iri::integer.clone());
+        let one_decimal = Literal::new_dt("1.0", xsd::iri::decimal.clone());
+        let one_double = Literal::new_dt("1.0E0", xsd::iri::double.clone());
+
+        assert!(one_int.value_eq(&one_int_canonical));
+        assert!(one_int.value_eq(&one_decimal));
+        assert!(one_decimal.value_eq(&one_double));
+        assert!(one_int.value_eq(&one_double));
+        // term equality still distinguishes them
+        assert_ne!(one_int, one_decimal);
+    }
+
+    #[test]
+    fn value_eq_compares_date_time_by_instant_across_offsets() {
+        let utc = Literal::new_dt("2020-01-01T10:00:00Z", xsd::iri::dateTime.clone());
+        let plus_one = Literal::new_dt("2020-01-01T11:00:00+01:00", xsd::iri::dateTime.clone());
+        let different_instant =
+            Literal::new_dt("2020-01-01T11:00:00Z", xsd::iri::dateTime.clone());
+
+        assert!(utc.value_eq(&plus_one));
+        assert!(!utc.value_eq(&different_instant));
+    }
+
+    #[test]
+    fn value_eq_falls_back_to_term_eq_for_ill_typed_literals() {
+        let ill_typed = Literal::new_dt("not a number", xsd::iri::integer.clone());
+        let same_text = Literal::new_dt("not a number", xsd::iri::integer.clone());
+        let other_text = Literal::new_dt("still not a number", xsd::iri::integer.clone());
+
+        assert!(ill_typed.value_eq(&same_text));
+        assert!(!ill_typed.value_eq(&other_text));
+    }
+
+    #[test]
+    fn lang_matches_trailing_wildcard() {
+        let lit = Literal::new_lang("hello", "en").unwrap();
+        assert!(lit.lang_matches("en-*"));
+        assert!(lit.lang_matches("*"));
+        assert!(!lit.lang_matches("fr-*"));
+    }
+
+    #[test]
+    fn lang_matches_mid_range_wildcard_rfc4647_example() {
+        // RFC 4647 section 3.3.2's own worked example.
+        let lit = Literal::new_lang("hallo", "de-DE").unwrap();
+        assert!(lit.lang_matches("de-*-DE"));
+    }
+
+    #[test]
+    fn lang_matches_basic_is_prefix_only() {
+        let lit = Literal::new_lang("hello", "en-US").unwrap();
+        assert!(lit.lang_matches_basic("en"));
+        assert!(lit.lang_matches_basic("en-US"));
+        assert!(!lit.lang_matches_basic("en-u"));
+    }
+
+    #[test]
+    fn lang_matches_is_false_for_non_lang_literal() {
+        let lit = Literal::new_dt("42", xsd::iri::integer.clone());
+        assert!(!lit.lang_matches("*"));
+        assert!(!lit.lang_matches_basic("*"));
+    }
+
+    #[test]
+    fn write_canonical_fmt_escapes_control_characters() {
+        let lit = Literal::new_dt("a\tb\u{0}c", xsd::iri::string.clone());
+        let mut out = String::new();
+        lit.write_canonical_fmt(&mut out).unwrap();
+        assert_eq!(out, "\"a\\tb\\u0000c\"");
+    }
+
+    #[test]
+    fn write_turtle_fmt_bare_boolean_and_integer() {
+        let mut out = String::new();
+        Literal::new_dt("true", xsd::iri::boolean.clone())
+            .write_turtle_fmt(&mut out)
+            .unwrap();
+        assert_eq!(out, "true");
+
+        out.clear();
+        Literal::new_dt("-42", xsd::iri::integer.clone())
+            .write_turtle_fmt(&mut out)
+            .unwrap();
+        assert_eq!(out, "-42");
+    }
+
+    #[test]
+    fn write_turtle_fmt_requires_a_digit_after_the_decimal_point() {
+        let mut out = String::new();
+        Literal::new_dt("5.0", xsd::iri::decimal.clone())
+            .write_turtle_fmt(&mut out)
+            .unwrap();
+        assert_eq!(out, "5.0");
+
+        // "5." is a legal xsd:decimal lexical form, but not a legal Turtle
+        // DECIMAL token: it must fall back to the quoted form.
+        out.clear();
+        Literal::new_dt("5.", xsd::iri::decimal.clone())
+            .write_turtle_fmt(&mut out)
+            .unwrap();
+        assert!(out.starts_with('"'));
+    }
+
+    #[test]
+    fn write_turtle_fmt_uses_long_string_for_newlines() {
+        let lit = Literal::new_dt("line one\nline two", xsd::iri::string.clone());
+        let mut out = String::new();
+        lit.write_turtle_fmt(&mut out).unwrap();
+        assert_eq!(out, "\"\"\"line one\nline two\"\"\"");
+    }
+
+    #[test]
+    fn value_as_date_time_rejects_non_canonical_digit_widths() {
+        let lit = Literal::new_dt("2020-1-1T1:0:0", xsd::iri::dateTime.clone());
+        assert!(!lit.is_well_typed());
+        assert!(lit.value_as::<XsdDateTime>().is_err());
+    }
+
+    #[test]
+    fn value_as_date_rejects_non_canonical_digit_widths() {
+        let lit = Literal::new_dt("2020-1-1", xsd::iri::date.clone());
+        assert!(!lit.is_well_typed());
+        assert!(lit.value_as::<XsdDateTime>().is_err());
+    }
+
+    #[test]
+    fn value_as_double_rejects_lowercase_inf_and_nan() {
+        for txt in ["inf", "-inf", "infinity", "nan", "NAN"] {
+            let lit = Literal::new_dt(txt, xsd::iri::double.clone());
+            assert!(!lit.is_well_typed(), "{} should not be well-typed", txt);
+            assert!(
+                lit.value_as::<f64>().is_err(),
+                "{} should not parse as f64",
+                txt
+            );
+        }
+    }
+
+    #[test]
+    fn value_as_decimal_rejects_inf_and_nan() {
+        for txt in ["INF", "-INF", "NaN", "inf"] {
+            let lit = Literal::new_dt(txt, xsd::iri::decimal.clone());
+            assert!(!lit.is_well_typed(), "{} should not be well-typed", txt);
+            assert!(
+                lit.value_as::<f64>().is_err(),
+                "{} should not parse as f64",
+                txt
+            );
+        }
+    }
 }
\ No newline at end of file
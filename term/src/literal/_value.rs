@@ -0,0 +1,296 @@
+//! The lexical-to-value mapping for the common XSD datatypes.
+//!
+//! See [`Literal::value_as`](../struct.Literal.html#method.value_as).
+
+use crate::ns::xsd;
+use crate::{Iri, Result, TermError};
+
+/// Converts the lexical form of a typed literal into a native Rust value.
+///
+/// This implements the lexical-to-value mapping described in the
+/// [module documentation](super) for a given Rust type: given the text of a
+/// literal and the datatype IRI it is tagged with, produce the
+/// corresponding value in the datatype's value space.
+///
+/// An implementation must return `Err` rather than panic when `txt` is not
+/// in the lexical space of `dt`, since [`Literal`](super::Literal)
+/// deliberately accepts such ill-typed literals.
+pub trait FromLexical: Sized {
+    /// Parse `txt`, the lexical form of a literal typed as `dt`.
+    fn from_lexical(txt: &str, dt: Iri<&str>) -> Result<Self>;
+}
+
+fn unexpected_datatype(dt: Iri<&str>, expect: &str) -> TermError {
+    TermError::UnexpectedDatatype {
+        dt: dt.to_string(),
+        expect: expect.to_owned(),
+    }
+}
+
+fn invalid_lexical_form(txt: &str, dt: Iri<&str>) -> TermError {
+    TermError::InvalidLexicalForm {
+        txt: txt.to_owned(),
+        dt: dt.to_string(),
+    }
+}
+
+macro_rules! impl_from_lexical_integer {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl FromLexical for $ty {
+                fn from_lexical(txt: &str, dt: Iri<&str>) -> Result<Self> {
+                    if dt != xsd::integer && dt != xsd::int && dt != xsd::long {
+                        return Err(unexpected_datatype(dt, "xsd:integer, xsd:int or xsd:long"));
+                    }
+                    txt.parse::<$ty>()
+                        .map_err(|_| invalid_lexical_form(txt, dt))
+                }
+            }
+        )+
+    };
+}
+
+impl_from_lexical_integer!(i64, i128);
+
+impl FromLexical for f64 {
+    fn from_lexical(txt: &str, dt: Iri<&str>) -> Result<Self> {
+        if dt == xsd::double || dt == xsd::float {
+            return match txt {
+                "INF" => Ok(f64::INFINITY),
+                "-INF" => Ok(f64::NEG_INFINITY),
+                "NaN" => Ok(f64::NAN),
+                // Rust's float parser is far more permissive than the XSD
+                // lexical space (it also accepts e.g. "inf", "infinity" or
+                // "nan" in any case), so the lexical space must be checked
+                // explicitly rather than just deferring to `txt.parse()`.
+                _ if super::DOUBLE_RE.is_match(txt) => {
+                    txt.parse().map_err(|_| invalid_lexical_form(txt, dt))
+                }
+                _ => Err(invalid_lexical_form(txt, dt)),
+            };
+        }
+        if dt == xsd::decimal {
+            return if super::DECIMAL_RE.is_match(txt) {
+                txt.parse().map_err(|_| invalid_lexical_form(txt, dt))
+            } else {
+                Err(invalid_lexical_form(txt, dt))
+            };
+        }
+        Err(unexpected_datatype(dt, "xsd:decimal, xsd:double or xsd:float"))
+    }
+}
+
+impl FromLexical for bool {
+    fn from_lexical(txt: &str, dt: Iri<&str>) -> Result<Self> {
+        if dt != xsd::boolean {
+            return Err(unexpected_datatype(dt, "xsd:boolean"));
+        }
+        match txt {
+            "true" | "1" => Ok(true),
+            "false" | "0" => Ok(false),
+            _ => Err(invalid_lexical_form(txt, dt)),
+        }
+    }
+}
+
+impl FromLexical for String {
+    /// Always succeeds: `rdf:langString` (and any other datatype) maps to
+    /// its lexical text as-is.
+    fn from_lexical(txt: &str, _dt: Iri<&str>) -> Result<Self> {
+        Ok(txt.to_owned())
+    }
+}
+
+/// The value denoted by an `xsd:dateTime` or `xsd:date` literal.
+///
+/// Mirrors the fields of a `chrono::NaiveDateTime` plus an optional UTC
+/// offset, without requiring a dependency on `chrono` itself.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct XsdDateTime {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+    pub nanosecond: u32,
+    /// The offset from UTC in minutes, if the lexical form carried a
+    /// timezone (`Z` or `[+-]HH:MM`).
+    pub offset_minutes: Option<i16>,
+}
+
+impl FromLexical for XsdDateTime {
+    fn from_lexical(txt: &str, dt: Iri<&str>) -> Result<Self> {
+        if dt == xsd::dateTime {
+            parse_date_time(txt, dt)
+        } else if dt == xsd::date {
+            parse_date(txt, dt)
+        } else {
+            Err(unexpected_datatype(dt, "xsd:dateTime or xsd:date"))
+        }
+    }
+}
+
+impl XsdDateTime {
+    /// A key that orders and compares consistently with the UTC instant
+    /// this value denotes (UTC is assumed where no offset was given).
+    ///
+    /// Used to implement [`Literal::value_eq`](super::Literal::value_eq)
+    /// for `xsd:dateTime`/`xsd:date`, where two literals with different
+    /// offsets can denote the same instant.
+    pub fn to_utc_key(&self) -> (i64, i64, u32) {
+        let mut day = julian_day(self.year, self.month, self.day);
+        let mut seconds =
+            self.hour as i64 * 3600 + self.minute as i64 * 60 + self.second as i64;
+        seconds -= i64::from(self.offset_minutes.unwrap_or(0)) * 60;
+        while seconds < 0 {
+            seconds += 86400;
+            day -= 1;
+        }
+        while seconds >= 86400 {
+            seconds -= 86400;
+            day += 1;
+        }
+        (day, seconds, self.nanosecond)
+    }
+}
+
+/// Proleptic-Gregorian Julian day number, used to compare dates across
+/// month/year boundaries without pulling in a calendar dependency.
+fn julian_day(year: i32, month: u8, day: u8) -> i64 {
+    let m = i64::from(month);
+    let d = i64::from(day);
+    let a = (14 - m) / 12;
+    let y = i64::from(year) + 4800 - a;
+    let m = m + 12 * a - 3;
+    d + (153 * m + 2) / 5 + 365 * y + y / 4 - y / 100 + y / 400 - 32045
+}
+
+fn parse_date(txt: &str, dt: Iri<&str>) -> Result<XsdDateTime> {
+    // `parse_ymd` and the field parsing below don't enforce XSD's digit-width
+    // constraints (4+ digit year, exactly 2 digits for month/day) on their
+    // own, so `value_as` must never accept anything `is_well_typed` (which
+    // checks the same lexical space via this same regex) would reject.
+    if !super::DATE_RE.is_match(txt) {
+        return Err(invalid_lexical_form(txt, dt));
+    }
+    let (date_part, tz_part) = split_timezone(txt, 10);
+    let (year, month, day) = parse_ymd(date_part).ok_or_else(|| invalid_lexical_form(txt, dt))?;
+    let offset_minutes = parse_offset(tz_part, txt, dt)?;
+    Ok(XsdDateTime {
+        year,
+        month,
+        day,
+        hour: 0,
+        minute: 0,
+        second: 0,
+        nanosecond: 0,
+        offset_minutes,
+    })
+}
+
+fn parse_date_time(txt: &str, dt: Iri<&str>) -> Result<XsdDateTime> {
+    // See the comment in `parse_date`: this guards the same digit-width
+    // constraints for the date, hour, minute and second fields.
+    if !super::DATE_TIME_RE.is_match(txt) {
+        return Err(invalid_lexical_form(txt, dt));
+    }
+    let t_pos = txt.find('T').ok_or_else(|| invalid_lexical_form(txt, dt))?;
+    let date_part = &txt[..t_pos];
+    let (time_part, tz_part) = split_timezone(&txt[t_pos + 1..], 0);
+    let (year, month, day) = parse_ymd(date_part).ok_or_else(|| invalid_lexical_form(txt, dt))?;
+    let mut tp = time_part.splitn(3, ':');
+    let hour: u8 = tp
+        .next()
+        .and_then(|s| s.parse().ok())
+        .ok_or_else(|| invalid_lexical_form(txt, dt))?;
+    let minute: u8 = tp
+        .next()
+        .and_then(|s| s.parse().ok())
+        .ok_or_else(|| invalid_lexical_form(txt, dt))?;
+    let (second, nanosecond) = tp
+        .next()
+        .and_then(parse_seconds)
+        .ok_or_else(|| invalid_lexical_form(txt, dt))?;
+    if hour > 24 || minute > 59 || second > 60 || month == 0 || month > 12 || day == 0 || day > 31 {
+        return Err(invalid_lexical_form(txt, dt));
+    }
+    let offset_minutes = parse_offset(tz_part, txt, dt)?;
+    Ok(XsdDateTime {
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        second,
+        nanosecond,
+        offset_minutes,
+    })
+}
+
+/// Splits `txt` into its timezone-free prefix and the raw `Z`/`+HH:MM`/`-HH:MM` suffix,
+/// looking for the suffix only in `txt[from..]`; the prefix includes `txt[..from]`.
+fn split_timezone(txt: &str, from: usize) -> (&str, &str) {
+    let tail = &txt[from..];
+    if let Some(pos) = tail.find(|c| c == 'Z' || c == '+' || c == '-') {
+        (&txt[..from + pos], &tail[pos..])
+    } else {
+        (txt, "")
+    }
+}
+
+fn parse_offset(tz: &str, txt: &str, dt: Iri<&str>) -> Result<Option<i16>> {
+    if tz.is_empty() {
+        return Ok(None);
+    }
+    if tz == "Z" {
+        return Ok(Some(0));
+    }
+    let sign: i16 = match tz.as_bytes()[0] {
+        b'+' => 1,
+        b'-' => -1,
+        _ => return Err(invalid_lexical_form(txt, dt)),
+    };
+    let mut it = tz[1..].splitn(2, ':');
+    let h: i16 = it
+        .next()
+        .and_then(|s| s.parse().ok())
+        .ok_or_else(|| invalid_lexical_form(txt, dt))?;
+    let m: i16 = it
+        .next()
+        .and_then(|s| s.parse().ok())
+        .ok_or_else(|| invalid_lexical_form(txt, dt))?;
+    Ok(Some(sign * (h * 60 + m)))
+}
+
+fn parse_ymd(s: &str) -> Option<(i32, u8, u8)> {
+    let (neg, s) = if s.starts_with('-') {
+        (true, &s[1..])
+    } else {
+        (false, s)
+    };
+    let mut it = s.splitn(3, '-');
+    let y: i32 = it.next()?.parse().ok()?;
+    let m: u8 = it.next()?.parse().ok()?;
+    let d: u8 = it.next()?.parse().ok()?;
+    if m == 0 || m > 12 || d == 0 || d > 31 {
+        return None;
+    }
+    Some((if neg { -y } else { y }, m, d))
+}
+
+fn parse_seconds(s: &str) -> Option<(u8, u32)> {
+    let mut it = s.splitn(2, '.');
+    let sec: u8 = it.next()?.parse().ok()?;
+    let nanosecond = match it.next() {
+        Some(frac) => {
+            let mut digits: String = frac.chars().take(9).collect();
+            while digits.len() < 9 {
+                digits.push('0');
+            }
+            digits.parse().ok()?
+        }
+        None => 0,
+    };
+    Some((sec, nanosecond))
+}